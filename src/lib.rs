@@ -1,60 +1,113 @@
 /**
  * BezierEasing Rust - use bezier curve for transition easing function
- * 
+ *
  * This is a rust port of Gaëtan Renaudeau's bezier-easing from https://github.com/gre/bezier-easing
  * by 2024 Genkagaku – MIT License
  */
-type BFloat = f32;
+use std::ops::{Add, Div, Mul, Sub};
+
+/// The float type a [`BezierEasing`] is evaluated over.
+///
+/// Implemented for `f32` and `f64` so callers can pick `BezierEasing<f32>`
+/// for throughput or `BezierEasing<f64>` for precision-sensitive timing
+/// (e.g. matching Servo's or WebKit's `f64` bezier implementations).
+pub trait BezierFloat:
+    Copy
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+{
+    fn from_f64(value: f64) -> Self;
+    fn abs(self) -> Self;
+}
+
+impl BezierFloat for f32 {
+    #[inline]
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+
+    #[inline]
+    fn abs(self) -> Self {
+        f32::abs(self)
+    }
+}
+
+impl BezierFloat for f64 {
+    #[inline]
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+
+    #[inline]
+    fn abs(self) -> Self {
+        f64::abs(self)
+    }
+}
 
 const NEWTON_ITERATIONS: usize = 4;
-const NEWTON_MIN_SLOPE: BFloat = 0.001;
-const SUBDIVISION_PRECISION: BFloat = 0.0000001;
 const SUBDIVISION_MAX_ITERATIONS: usize = 10;
 
 const K_SPLINE_TABLE_SIZE: usize = 11;
-const K_SAMPLE_STEP_SIZE: BFloat = 1.0 / (K_SPLINE_TABLE_SIZE - 1) as BFloat;
 
 #[inline]
-fn a(a_a1: BFloat, a_a2: BFloat) -> BFloat {
-    1.0 - 3.0 * a_a2 + 3.0 * a_a1
+fn newton_min_slope<T: BezierFloat>() -> T {
+    T::from_f64(0.001)
 }
 
 #[inline]
-fn b(a_a1: BFloat, a_a2: BFloat) -> BFloat {
-    3.0 * a_a2 - 6.0 * a_a1
+fn subdivision_precision<T: BezierFloat>() -> T {
+    T::from_f64(0.0000001)
 }
 
 #[inline]
-fn c(a_a1: BFloat) -> BFloat {
-    3.0 * a_a1
+fn k_sample_step_size<T: BezierFloat>() -> T {
+    T::from_f64(1.0) / T::from_f64((K_SPLINE_TABLE_SIZE - 1) as f64)
 }
 
 #[inline]
-fn calc_bezier(a_t: BFloat, a_a1: BFloat, a_a2: BFloat) -> BFloat {
+fn a<T: BezierFloat>(a_a1: T, a_a2: T) -> T {
+    T::from_f64(1.0) - T::from_f64(3.0) * a_a2 + T::from_f64(3.0) * a_a1
+}
+
+#[inline]
+fn b<T: BezierFloat>(a_a1: T, a_a2: T) -> T {
+    T::from_f64(3.0) * a_a2 - T::from_f64(6.0) * a_a1
+}
+
+#[inline]
+fn c<T: BezierFloat>(a_a1: T) -> T {
+    T::from_f64(3.0) * a_a1
+}
+
+#[inline]
+fn calc_bezier<T: BezierFloat>(a_t: T, a_a1: T, a_a2: T) -> T {
     ((a(a_a1, a_a2) * a_t + b(a_a1, a_a2)) * a_t + c(a_a1)) * a_t
 }
 
 #[inline]
-fn get_slope(a_t: BFloat, a_a1: BFloat, a_a2: BFloat) -> BFloat {
-    3.0 * a(a_a1, a_a2) * a_t * a_t + 2.0 * b(a_a1, a_a2) * a_t + c(a_a1)
+fn get_slope<T: BezierFloat>(a_t: T, a_a1: T, a_a2: T) -> T {
+    T::from_f64(3.0) * a(a_a1, a_a2) * a_t * a_t + T::from_f64(2.0) * b(a_a1, a_a2) * a_t + c(a_a1)
 }
 
 #[inline]
-fn binary_subdivide(a_x: BFloat, a_a: BFloat, a_b: BFloat, m_x1: BFloat, m_x2: BFloat) -> BFloat {
+fn binary_subdivide<T: BezierFloat>(a_x: T, a_a: T, a_b: T, m_x1: T, m_x2: T, epsilon: T) -> T {
     let mut m_x1 = m_x1;
     let mut m_x2 = m_x2;
-    let mut current_x: BFloat;
-    let mut current_t = 0.0;
+    let mut current_x: T;
+    let mut current_t = T::from_f64(0.0);
     let mut i = 0;
     while i < SUBDIVISION_MAX_ITERATIONS {
-        current_t = m_x1 + (m_x2 - m_x1) / 2.0;
+        current_t = m_x1 + (m_x2 - m_x1) / T::from_f64(2.0);
         current_x = calc_bezier(current_t, a_a, a_b) - a_x;
-        if current_x > 0.0 {
+        if current_x > T::from_f64(0.0) {
             m_x2 = current_t;
         } else {
             m_x1 = current_t;
         }
-        if current_x.abs() < SUBDIVISION_PRECISION {
+        if current_x.abs() < epsilon {
             break;
         }
         i += 1;
@@ -62,86 +115,425 @@ fn binary_subdivide(a_x: BFloat, a_a: BFloat, a_b: BFloat, m_x1: BFloat, m_x2: B
     current_t
 }
 
-fn newton_raphson_iterate(a_x: BFloat, a_guess_t: BFloat, a_a: BFloat, a_b: BFloat) -> BFloat {
+fn newton_raphson_iterate<T: BezierFloat>(a_x: T, a_guess_t: T, a_a: T, a_b: T, epsilon: T) -> T {
     let mut guess_t = a_guess_t;
     for _ in 0..NEWTON_ITERATIONS {
         let current_slope = get_slope(guess_t, a_a, a_b);
-        if current_slope == 0.0 {
+        if current_slope == T::from_f64(0.0) {
             return guess_t;
         }
         let current_x = calc_bezier(guess_t, a_a, a_b) - a_x;
-        guess_t -= current_x / current_slope;
+        if current_x.abs() < epsilon {
+            return guess_t;
+        }
+        guess_t = guess_t - current_x / current_slope;
     }
     guess_t
 }
 
 #[inline]
-fn linear_easing(x: BFloat) -> BFloat {
+fn linear_easing<T: BezierFloat>(x: T) -> T {
     x
 }
 
 #[inline]
-fn calc_sample_values(m_x1: BFloat, m_x2: BFloat) -> [BFloat; K_SPLINE_TABLE_SIZE] {
-    let mut sample_values = [0.0; K_SPLINE_TABLE_SIZE];
+fn calc_sample_values<T: BezierFloat>(m_x1: T, m_x2: T) -> [T; K_SPLINE_TABLE_SIZE] {
+    let mut sample_values = [T::from_f64(0.0); K_SPLINE_TABLE_SIZE];
     for (i, value) in sample_values.iter_mut().enumerate() {
-        *value = calc_bezier(i as BFloat * K_SAMPLE_STEP_SIZE, m_x1, m_x2);
+        *value = calc_bezier(T::from_f64(i as f64) * k_sample_step_size(), m_x1, m_x2);
     }
     sample_values
 }
 
 #[inline]
-fn get_t_for_x(x: BFloat, m_x1: BFloat, m_x2: BFloat) -> BFloat {
-    let mut interval_start = 0.0;
+fn get_t_for_x<T: BezierFloat>(
+    x: T,
+    m_x1: T,
+    m_x2: T,
+    sample_values: &[T; K_SPLINE_TABLE_SIZE],
+    epsilon: T,
+) -> T {
+    let step = k_sample_step_size();
+    let mut interval_start = T::from_f64(0.0);
     let mut current_sample = 1;
     let last_sample = K_SPLINE_TABLE_SIZE - 1;
-    let sample_values = calc_sample_values(m_x1, m_x2);
 
     while current_sample != last_sample && sample_values[current_sample] <= x {
-        interval_start += K_SAMPLE_STEP_SIZE;
+        interval_start = interval_start + step;
         current_sample += 1;
     }
     current_sample -= 1;
 
     let dist = (x - sample_values[current_sample])
         / (sample_values[current_sample + 1] - sample_values[current_sample]);
-    let guess_for_t = interval_start + dist * K_SAMPLE_STEP_SIZE;
+    let guess_for_t = interval_start + dist * step;
     let initial_slope = get_slope(guess_for_t, m_x1, m_x2);
-    if initial_slope >= NEWTON_MIN_SLOPE {
-        newton_raphson_iterate(x, guess_for_t, m_x1, m_x2)
-    } else if initial_slope == 0.0 {
+    if initial_slope >= newton_min_slope() {
+        newton_raphson_iterate(x, guess_for_t, m_x1, m_x2, epsilon)
+    } else if initial_slope == T::from_f64(0.0) {
         guess_for_t
     } else {
-        binary_subdivide(
-            x,
-            interval_start,
-            interval_start + K_SAMPLE_STEP_SIZE,
-            m_x1,
-            m_x2,
-        )
+        binary_subdivide(x, interval_start, interval_start + step, m_x1, m_x2, epsilon)
     }
 }
 
 #[derive(Debug)]
-pub struct BezierEasingError(String);
+pub enum BezierEasingError {
+    /// A control point was rejected: for [`BezierEasing`], `m_x1` or `m_x2`
+    /// fell outside of `[0, 1]`; for [`BezierEasingN`], there were fewer than
+    /// 3 control points, a control point's x fell outside of `[0, 1]`, or the
+    /// first and last control points were not at x=0 and x=1.
+    InvalidControlPoint(String),
+    /// A CSS `cubic-bezier()` string (or keyword) could not be parsed.
+    ParseError(String),
+    /// A [`BezierEasingN`] control polygon produces an x(t) that is not
+    /// monotonically increasing, so a given `x` would map to more than one
+    /// `t`. Unreachable for [`BezierEasing`]: with endpoints fixed at
+    /// `(0, 0)`/`(1, 1)` and `m_x1, m_x2 ∈ [0, 1]`, its cubic x(t) is always
+    /// monotonic.
+    NonMonotonic(String),
+}
 
-pub fn bezier_easing(
-    m_x1: BFloat,
-    m_y1: BFloat,
-    m_x2: BFloat,
-    m_y2: BFloat,
-) -> Result<impl Fn(BFloat) -> BFloat, BezierEasingError> {
-    if !((0.0..=1.0).contains(&m_x1) && (0.0..=1.0).contains(&m_x2)) {
-        return Err(BezierEasingError("x values must be in [0, 1]".to_string()));
-    }
-    Ok(move |x: BFloat| {
-        if m_x1 == m_y1 && m_x2 == m_y2 {
+#[inline]
+fn check_monotonic<T: BezierFloat>(
+    sample_values: &[T; K_SPLINE_TABLE_SIZE],
+    epsilon: T,
+) -> Result<(), BezierEasingError> {
+    for window in sample_values.windows(2) {
+        if window[0] - window[1] > epsilon {
+            return Err(BezierEasingError::NonMonotonic(
+                "control points produce a non-monotonic x(t); a given x may map to multiple t values"
+                    .to_string(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// A cubic bezier easing function with a precomputed spline sample table.
+///
+/// Building a `BezierEasing` computes the `K_SPLINE_TABLE_SIZE`-entry sample
+/// table once; [`BezierEasing::ease`] then reuses that table for every
+/// evaluation instead of rebuilding it per call, which matters when the
+/// curve is sampled thousands of times (e.g. once per animation frame).
+///
+/// Generic over `T: BezierFloat` so callers can instantiate
+/// `BezierEasing<f32>` for throughput or `BezierEasing<f64>` when the extra
+/// precision matters.
+pub struct BezierEasing<T: BezierFloat> {
+    m_x1: T,
+    m_y1: T,
+    m_x2: T,
+    m_y2: T,
+    sample_values: [T; K_SPLINE_TABLE_SIZE],
+    epsilon: T,
+}
+
+impl<T: BezierFloat> BezierEasing<T> {
+    pub fn new(m_x1: T, m_y1: T, m_x2: T, m_y2: T) -> Result<Self, BezierEasingError> {
+        Self::with_epsilon(m_x1, m_y1, m_x2, m_y2, subdivision_precision())
+    }
+
+    /// Builds a `BezierEasing` whose root-finding stops as soon as it is
+    /// within `epsilon` of the target `x`, instead of the default
+    /// `1e-7` precision. Callers solving over a known pixel/duration scale
+    /// can trade precision for speed, e.g. `epsilon = 1.0 / (200.0 * duration)`
+    /// so an animation only solves `t` to the precision it can actually display.
+    pub fn with_epsilon(
+        m_x1: T,
+        m_y1: T,
+        m_x2: T,
+        m_y2: T,
+        epsilon: T,
+    ) -> Result<Self, BezierEasingError> {
+        let unit_range = T::from_f64(0.0)..=T::from_f64(1.0);
+        if !(unit_range.contains(&m_x1) && unit_range.contains(&m_x2)) {
+            return Err(BezierEasingError::InvalidControlPoint(
+                "x values must be in [0, 1]".to_string(),
+            ));
+        }
+        let sample_values = calc_sample_values(m_x1, m_x2);
+        Ok(Self {
+            m_x1,
+            m_y1,
+            m_x2,
+            m_y2,
+            sample_values,
+            epsilon,
+        })
+    }
+
+    pub fn ease(&self, x: T) -> T {
+        if self.m_x1 == self.m_y1 && self.m_x2 == self.m_y2 {
             return linear_easing(x);
         }
-        if x == 0.0 || x == 1.0 {
+        if x == T::from_f64(0.0) || x == T::from_f64(1.0) {
             return x;
         }
-        calc_bezier(get_t_for_x(x, m_x1, m_x2), m_y1, m_y2)
-    })
+        let t = get_t_for_x(
+            x,
+            self.m_x1,
+            self.m_x2,
+            &self.sample_values,
+            self.epsilon,
+        );
+        calc_bezier(t, self.m_y1, self.m_y2)
+    }
+
+    /// Builds a `BezierEasing` from a CSS `cubic-bezier()` function or one of
+    /// the keyword aliases the CSS spec defines (`linear`, `ease`,
+    /// `ease-in`, `ease-out`, `ease-in-out`), e.g. to drive an easing curve
+    /// straight from a theme/config string.
+    pub fn from_css(s: &str) -> Result<Self, BezierEasingError> {
+        let (x1, y1, x2, y2) = match s.trim() {
+            "linear" => (0.0, 0.0, 1.0, 1.0),
+            "ease" => (0.25, 0.1, 0.25, 1.0),
+            "ease-in" => (0.42, 0.0, 1.0, 1.0),
+            "ease-out" => (0.0, 0.0, 0.58, 1.0),
+            "ease-in-out" => (0.42, 0.0, 0.58, 1.0),
+            other => parse_cubic_bezier(other)?,
+        };
+        Self::new(
+            T::from_f64(x1),
+            T::from_f64(y1),
+            T::from_f64(x2),
+            T::from_f64(y2),
+        )
+    }
+}
+
+fn parse_cubic_bezier(s: &str) -> Result<(f64, f64, f64, f64), BezierEasingError> {
+    let inner = s
+        .trim()
+        .strip_prefix("cubic-bezier(")
+        .and_then(|rest| rest.strip_suffix(')'))
+        .ok_or_else(|| {
+            BezierEasingError::ParseError(format!(
+                "expected `cubic-bezier(x1, y1, x2, y2)` or a named easing keyword, got `{s}`"
+            ))
+        })?;
+
+    let parts: Vec<&str> = inner.split(',').map(str::trim).collect();
+    if parts.len() != 4 {
+        return Err(BezierEasingError::ParseError(format!(
+            "expected 4 control point values in `cubic-bezier(...)`, got {}",
+            parts.len()
+        )));
+    }
+
+    let mut values = [0.0_f64; 4];
+    for (value, part) in values.iter_mut().zip(parts.iter()) {
+        *value = part
+            .parse::<f64>()
+            .map_err(|e| BezierEasingError::ParseError(format!("invalid control point `{part}`: {e}")))?;
+    }
+    Ok((values[0], values[1], values[2], values[3]))
+}
+
+/// Evaluates the Bezier curve defined by `points` at parameter `t` via De
+/// Casteljau's algorithm: repeatedly lerp adjacent points until a single
+/// point remains.
+fn de_casteljau<T: BezierFloat>(points: &[(T, T)], t: T) -> (T, T) {
+    let mut pts = points.to_vec();
+    while pts.len() > 1 {
+        for i in 0..pts.len() - 1 {
+            let (x0, y0) = pts[i];
+            let (x1, y1) = pts[i + 1];
+            pts[i] = (x0 + (x1 - x0) * t, y0 + (y1 - y0) * t);
+        }
+        pts.pop();
+    }
+    pts[0]
+}
+
+/// The control points of the degree-(n-1) Bezier that is the derivative of
+/// the degree-n Bezier defined by `points`.
+fn bezier_derivative_points<T: BezierFloat>(points: &[(T, T)]) -> Vec<(T, T)> {
+    let degree = T::from_f64((points.len() - 1) as f64);
+    points
+        .windows(2)
+        .map(|pair| {
+            let (x0, y0) = pair[0];
+            let (x1, y1) = pair[1];
+            ((x1 - x0) * degree, (y1 - y0) * degree)
+        })
+        .collect()
+}
+
+fn calc_sample_values_n<T: BezierFloat>(points: &[(T, T)]) -> [T; K_SPLINE_TABLE_SIZE] {
+    let mut sample_values = [T::from_f64(0.0); K_SPLINE_TABLE_SIZE];
+    for (i, value) in sample_values.iter_mut().enumerate() {
+        let t = T::from_f64(i as f64) * k_sample_step_size();
+        *value = de_casteljau(points, t).0;
+    }
+    sample_values
+}
+
+fn binary_subdivide_n<T: BezierFloat>(
+    target_x: T,
+    mut t0: T,
+    mut t1: T,
+    epsilon: T,
+    eval_x: impl Fn(T) -> T,
+) -> T {
+    let mut current_t = T::from_f64(0.0);
+    let mut i = 0;
+    while i < SUBDIVISION_MAX_ITERATIONS {
+        current_t = t0 + (t1 - t0) / T::from_f64(2.0);
+        let current_x = eval_x(current_t) - target_x;
+        if current_x > T::from_f64(0.0) {
+            t1 = current_t;
+        } else {
+            t0 = current_t;
+        }
+        if current_x.abs() < epsilon {
+            break;
+        }
+        i += 1;
+    }
+    current_t
+}
+
+fn newton_raphson_iterate_n<T: BezierFloat>(
+    target_x: T,
+    a_guess_t: T,
+    epsilon: T,
+    eval_x: impl Fn(T) -> T,
+    eval_slope: impl Fn(T) -> T,
+) -> T {
+    let mut guess_t = a_guess_t;
+    for _ in 0..NEWTON_ITERATIONS {
+        let current_slope = eval_slope(guess_t);
+        if current_slope == T::from_f64(0.0) {
+            return guess_t;
+        }
+        let current_x = eval_x(guess_t) - target_x;
+        if current_x.abs() < epsilon {
+            return guess_t;
+        }
+        guess_t = guess_t - current_x / current_slope;
+    }
+    guess_t
+}
+
+fn get_t_for_x_n<T: BezierFloat>(
+    x: T,
+    control_points: &[(T, T)],
+    derivative_points: &[(T, T)],
+    sample_values: &[T; K_SPLINE_TABLE_SIZE],
+    epsilon: T,
+) -> T {
+    let step = k_sample_step_size();
+    let mut interval_start = T::from_f64(0.0);
+    let mut current_sample = 1;
+    let last_sample = K_SPLINE_TABLE_SIZE - 1;
+
+    while current_sample != last_sample && sample_values[current_sample] <= x {
+        interval_start = interval_start + step;
+        current_sample += 1;
+    }
+    current_sample -= 1;
+
+    let dist = (x - sample_values[current_sample])
+        / (sample_values[current_sample + 1] - sample_values[current_sample]);
+    let guess_for_t = interval_start + dist * step;
+
+    let eval_x = |t: T| de_casteljau(control_points, t).0;
+    let eval_slope = |t: T| de_casteljau(derivative_points, t).0;
+
+    let initial_slope = eval_slope(guess_for_t);
+    if initial_slope >= newton_min_slope() {
+        newton_raphson_iterate_n(x, guess_for_t, epsilon, eval_x, eval_slope)
+    } else if initial_slope == T::from_f64(0.0) {
+        guess_for_t
+    } else {
+        binary_subdivide_n(x, interval_start, interval_start + step, epsilon, eval_x)
+    }
+}
+
+/// An arbitrary-degree Bezier easing curve, for motion that a single cubic
+/// can't express (e.g. S-curves or overshoot profiles from quartic-or-higher
+/// control polygons).
+///
+/// `control_points` is the full control polygon, e.g. `[(0,0), (cx,cy), (1,1)]`
+/// for a quadratic curve or `[(0,0), (x1,y1), (x2,y2), (1,1)]` for a cubic
+/// one. Position is evaluated with De Casteljau's algorithm rather than the
+/// closed-form cubic coefficients [`BezierEasing`] uses, so it works for any
+/// degree at the cost of an O(n^2) evaluation per sample. [`BezierEasing`]
+/// remains the fast path for the common cubic case.
+pub struct BezierEasingN<T: BezierFloat> {
+    control_points: Vec<(T, T)>,
+    derivative_points: Vec<(T, T)>,
+    sample_values: [T; K_SPLINE_TABLE_SIZE],
+    epsilon: T,
+}
+
+impl<T: BezierFloat> BezierEasingN<T> {
+    pub fn new(control_points: &[(T, T)]) -> Result<Self, BezierEasingError> {
+        Self::with_epsilon(control_points, subdivision_precision())
+    }
+
+    pub fn with_epsilon(control_points: &[(T, T)], epsilon: T) -> Result<Self, BezierEasingError> {
+        if control_points.len() < 3 {
+            return Err(BezierEasingError::InvalidControlPoint(
+                "need at least 3 control points (a quadratic curve or higher)".to_string(),
+            ));
+        }
+        let unit_range = T::from_f64(0.0)..=T::from_f64(1.0);
+        for &(x, _) in control_points {
+            if !unit_range.contains(&x) {
+                return Err(BezierEasingError::InvalidControlPoint(
+                    "x values must be in [0, 1]".to_string(),
+                ));
+            }
+        }
+        if control_points[0].0 != T::from_f64(0.0)
+            || control_points[control_points.len() - 1].0 != T::from_f64(1.0)
+        {
+            return Err(BezierEasingError::InvalidControlPoint(
+                "the first control point must anchor at x=0 and the last at x=1".to_string(),
+            ));
+        }
+
+        let control_points = control_points.to_vec();
+        let derivative_points = bezier_derivative_points(&control_points);
+        let sample_values = calc_sample_values_n(&control_points);
+        check_monotonic(&sample_values, epsilon)?;
+
+        Ok(Self {
+            control_points,
+            derivative_points,
+            sample_values,
+            epsilon,
+        })
+    }
+
+    pub fn ease(&self, x: T) -> T {
+        if x == T::from_f64(0.0) {
+            return self.control_points[0].1;
+        }
+        if x == T::from_f64(1.0) {
+            return self.control_points[self.control_points.len() - 1].1;
+        }
+        let t = get_t_for_x_n(
+            x,
+            &self.control_points,
+            &self.derivative_points,
+            &self.sample_values,
+            self.epsilon,
+        );
+        de_casteljau(&self.control_points, t).1
+    }
+}
+
+pub fn bezier_easing(
+    m_x1: f32,
+    m_y1: f32,
+    m_x2: f32,
+    m_y2: f32,
+) -> Result<impl Fn(f32) -> f32, BezierEasingError> {
+    let easing = BezierEasing::new(m_x1, m_y1, m_x2, m_y2)?;
+    Ok(move |x: f32| easing.ease(x))
 }
 
 #[cfg(test)]
@@ -157,4 +549,128 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn reuses_cached_sample_table() -> Result<(), BezierEasingError> {
+        let easing = BezierEasing::new(0.0, 0.0, 1.0, 0.5)?;
+        assert_eq!(easing.ease(0.0), 0.0);
+        assert_eq!(easing.ease(0.5), 0.3125);
+        assert_eq!(easing.ease(1.0), 1.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn works_with_f64() -> Result<(), BezierEasingError> {
+        let easing = BezierEasing::<f64>::new(0.0, 0.0, 1.0, 0.5)?;
+        assert_eq!(easing.ease(0.0), 0.0);
+        assert_eq!(easing.ease(1.0), 1.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn with_epsilon_matches_default_within_tolerance() -> Result<(), BezierEasingError> {
+        let precise = BezierEasing::new(0.25, 0.1, 0.25, 1.0)?;
+        let loose = BezierEasing::with_epsilon(0.25, 0.1, 0.25, 1.0, 1.0 / (200.0 * 30.0))?;
+
+        for i in 0..=10 {
+            let x = i as f32 / 10.0;
+            assert!((precise.ease(x) - loose.ease(x)).abs() < 0.01);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_css_parses_cubic_bezier_function() -> Result<(), BezierEasingError> {
+        let ease: BezierEasing<f32> = BezierEasing::from_css("cubic-bezier(0.0, 0.0, 1.0, 0.5)")?;
+        assert_eq!(ease.ease(0.5), 0.3125);
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_css_parses_named_keywords() -> Result<(), BezierEasingError> {
+        let linear: BezierEasing<f32> = BezierEasing::from_css("linear")?;
+        assert_eq!(linear.ease(0.5), 0.5);
+
+        BezierEasing::<f32>::from_css("ease")?;
+        BezierEasing::<f32>::from_css("ease-in")?;
+        BezierEasing::<f32>::from_css("ease-out")?;
+        BezierEasing::<f32>::from_css("ease-in-out")?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_css_rejects_malformed_input() {
+        assert!(BezierEasing::<f32>::from_css("not-a-curve").is_err());
+        assert!(BezierEasing::<f32>::from_css("cubic-bezier(0.1, 0.2, 0.3)").is_err());
+        assert!(BezierEasing::<f32>::from_css("cubic-bezier(2.0, 0.0, 1.0, 1.0)").is_err());
+    }
+
+    #[test]
+    fn rejects_non_monotonic_sample_tables() {
+        let mut sample_values = [0.0_f32; K_SPLINE_TABLE_SIZE];
+        for (i, value) in sample_values.iter_mut().enumerate() {
+            *value = i as f32 * 0.1;
+        }
+        sample_values[5] = sample_values[4] - 0.2;
+
+        let result = check_monotonic(&sample_values, subdivision_precision::<f32>());
+        assert!(matches!(result, Err(BezierEasingError::NonMonotonic(_))));
+    }
+
+    #[test]
+    fn bezier_easing_n_matches_cubic_special_case() -> Result<(), BezierEasingError> {
+        let cubic = BezierEasing::<f32>::new(0.0, 0.0, 1.0, 0.5)?;
+        let n = BezierEasingN::new(&[(0.0, 0.0), (0.0, 0.0), (1.0, 0.5), (1.0, 1.0)])?;
+
+        for i in 0..=10 {
+            let x = i as f32 / 10.0;
+            assert!((cubic.ease(x) - n.ease(x)).abs() < 1e-4);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn bezier_easing_n_supports_quadratic_curves() -> Result<(), BezierEasingError> {
+        let n = BezierEasingN::new(&[(0.0, 0.0), (0.5, 1.0), (1.0, 1.0)])?;
+        assert_eq!(n.ease(0.0), 0.0);
+        assert_eq!(n.ease(1.0), 1.0);
+        assert!(n.ease(0.5) > 0.5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn bezier_easing_n_rejects_too_few_control_points() {
+        let result = BezierEasingN::<f32>::new(&[(0.0, 0.0), (1.0, 1.0)]);
+        assert!(matches!(
+            result,
+            Err(BezierEasingError::InvalidControlPoint(_))
+        ));
+    }
+
+    #[test]
+    fn bezier_easing_n_rejects_control_polygons_not_anchored_at_0_and_1() {
+        // Every control point's x is in [0, 1], but the curve itself only
+        // spans x in [0.2, 0.9], so x=0/x=1 are not real points on it.
+        let result = BezierEasingN::<f32>::new(&[(0.2, 0.1), (0.5, 0.5), (0.9, 0.9)]);
+        assert!(matches!(
+            result,
+            Err(BezierEasingError::InvalidControlPoint(_))
+        ));
+    }
+
+    #[test]
+    fn bezier_easing_n_honors_non_unit_endpoint_y() -> Result<(), BezierEasingError> {
+        let n = BezierEasingN::new(&[(0.0, 0.3), (0.5, 1.0), (1.0, 0.7)])?;
+        assert_eq!(n.ease(0.0), 0.3);
+        assert_eq!(n.ease(1.0), 0.7);
+
+        Ok(())
+    }
 }